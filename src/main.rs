@@ -1,6 +1,7 @@
+use std::fs;
 use std::sync::mpsc::sync_channel;
 
-use cassandra_cpp::{Cluster, LendingIterator};
+use cassandra_cpp::{Cluster, LendingIterator, SslContext, SslVerifyFlag};
 use nu_plugin::{serve_plugin, MsgPackSerializer, Plugin, PluginCommand};
 use nu_plugin::{EngineInterface, EvaluatedCall};
 use nu_protocol::{
@@ -9,6 +10,9 @@ use nu_protocol::{
 };
 use uuid::Uuid;
 
+const DEFAULT_CONTACT_POINTS: &str = "127.0.0.1";
+const DEFAULT_PORT: i64 = 9042;
+
 pub struct CassandraQueryPlugin {
     handle: tokio::runtime::Handle,
 }
@@ -39,8 +43,96 @@ impl PluginCommand for CassandraQuery {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .input_output_type(Type::Nothing, Type::table())
+            .input_output_types(vec![
+                (Type::Nothing, Type::table()),
+                (Type::table(), Type::Int),
+                (Type::record(), Type::Int),
+            ])
             .required("query", SyntaxShape::String, "CQL query to run")
+            .named(
+                "contact-points",
+                SyntaxShape::String,
+                "Comma-separated list of cluster contact points (default: 127.0.0.1)",
+                Some('c'),
+            )
+            .named(
+                "port",
+                SyntaxShape::Int,
+                "Cluster port (default: 9042)",
+                None,
+            )
+            .named(
+                "username",
+                SyntaxShape::String,
+                "Username for plain-text authentication",
+                None,
+            )
+            .named(
+                "password",
+                SyntaxShape::String,
+                "Password for plain-text authentication",
+                None,
+            )
+            .switch("tls", "Connect to the cluster over TLS", None)
+            .named(
+                "ca-cert",
+                SyntaxShape::Filepath,
+                "Path to a PEM-encoded CA certificate used to verify the cluster",
+                None,
+            )
+            .named(
+                "client-cert",
+                SyntaxShape::Filepath,
+                "Path to a PEM-encoded client certificate for mutual TLS",
+                None,
+            )
+            .named(
+                "client-key",
+                SyntaxShape::Filepath,
+                "Path to the PEM-encoded private key matching --client-cert",
+                None,
+            )
+            .named(
+                "params",
+                SyntaxShape::List(Box::new(SyntaxShape::Any)),
+                "Positional values to bind to `?` placeholders in the query",
+                Some('p'),
+            )
+            .switch(
+                "batch",
+                "Execute one statement per input row as a single CQL BATCH",
+                None,
+            )
+            .named(
+                "batch-type",
+                SyntaxShape::String,
+                "Batch type: logged, unlogged, or counter (default: logged)",
+                None,
+            )
+            .named(
+                "consistency",
+                SyntaxShape::String,
+                "Consistency level: one, two, three, quorum, all, local-one, local-quorum, \
+                 each-quorum, any, serial, local-serial (default: local-one)",
+                None,
+            )
+            .named(
+                "serial-consistency",
+                SyntaxShape::String,
+                "Serial consistency level: serial or local-serial",
+                None,
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "Per-request timeout",
+                None,
+            )
+            .switch(
+                "trace",
+                "Enable request tracing and print the trace, warnings, and custom payload to stderr",
+                None,
+            )
             .category(Category::Database)
     }
 
@@ -61,26 +153,91 @@ impl PluginCommand for CassandraQuery {
         plugin: &CassandraQueryPlugin,
         _engine: &EngineInterface,
         call: &EvaluatedCall,
-        _input: PipelineData,
+        input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
-        plugin.handle.block_on(run(&call))
+        let span = call.head;
+        let (has_input, rows) = match input {
+            PipelineData::Empty => (false, Vec::new()),
+            PipelineData::Value(Value::Nothing { .. }, _) => (false, Vec::new()),
+            other => (true, other.into_iter().collect::<Vec<_>>()),
+        };
+        plugin.handle.block_on(run(&call, has_input, rows, span))
     }
 }
 
-async fn run(call: &EvaluatedCall) -> Result<PipelineData, LabeledError> {
-    let span = call.head;
+async fn run(
+    call: &EvaluatedCall,
+    has_input: bool,
+    rows: Vec<Value>,
+    span: Span,
+) -> Result<PipelineData, LabeledError> {
     let query: String = call.req(0)?;
+
+    let contact_points = call
+        .get_flag::<String>("contact-points")?
+        .unwrap_or_else(|| DEFAULT_CONTACT_POINTS.to_owned());
+    let port = call.get_flag::<i64>("port")?.unwrap_or(DEFAULT_PORT);
+    let port = u16::try_from(port).map_err(|_| {
+        LabeledError::new("Invalid port").with_label("port must be between 0 and 65535", span)
+    })?;
+
     let mut cluster = Cluster::default();
-    cluster.set_contact_points("127.0.0.1").label(span)?;
+    cluster.set_contact_points(&contact_points).label(span)?;
+    cluster.set_port(port).label(span)?;
     cluster.set_load_balance_round_robin();
+
+    match (
+        call.get_flag::<String>("username")?,
+        call.get_flag::<String>("password")?,
+    ) {
+        (Some(username), Some(password)) => cluster.set_credentials(&username, &password),
+        (None, None) => {}
+        (Some(_), None) => {
+            return Err(LabeledError::new("Missing --password")
+                .with_label("--username was given but --password was not", span))
+        }
+        (None, Some(_)) => {
+            return Err(LabeledError::new("Missing --username")
+                .with_label("--password was given but --username was not", span))
+        }
+    }
+
+    if let Some(mut ssl) = ssl_context(call, span)? {
+        cluster.set_ssl(&mut ssl);
+    }
+
     let session = cluster.connect().await.label(span)?;
-    let mut statement = session.statement(&query);
+    let options = StatementOptions::from_call(call, span)?;
+    let trace = call.has_flag("trace")?;
+
+    if has_input {
+        return run_writes(&session, &query, rows, call, &options, trace, span).await;
+    }
+
+    let params: Option<Vec<Value>> = call.get_flag("params")?;
+    let mut statement = if let Some(params) = &params {
+        let prepared = session.prepare(&query).await.label(span)?;
+        let mut statement = prepared.bind();
+        for (idx, value) in params.iter().enumerate() {
+            let param_type = prepared.parameter_data_type(idx).label(span)?.get_type();
+            bind_nu_value(&mut statement, BindTarget::Index(idx), param_type, value)?;
+        }
+        statement
+    } else {
+        session.statement(&query)
+    };
+    options.apply_to_statement(&mut statement, span)?;
     statement.set_paging_size(1024).label(span)?;
-    let mut result = session
+    if trace {
+        statement.set_tracing(true).label(span)?;
+    }
+    let (mut result, payload) = session
         .execute_with_payloads(&statement)
         .await
-        .label(span)?
-        .0;
+        .label(span)?;
+    if trace {
+        report_trace(&session, &result, Some(&payload), span).await?;
+    }
     let (tx, rx) = sync_channel(1024);
 
     let columns = (0..result.column_count())
@@ -133,6 +290,389 @@ async fn run(call: &EvaluatedCall) -> Result<PipelineData, LabeledError> {
     ))
 }
 
+/// Builds an `SslContext` from the `--tls`/`--ca-cert`/`--client-cert`/`--client-key` flags,
+/// returning `None` when TLS wasn't requested.
+fn ssl_context(call: &EvaluatedCall, span: Span) -> Result<Option<SslContext>, LabeledError> {
+    let ca_cert = call.get_flag::<String>("ca-cert")?;
+    let client_cert = call.get_flag::<String>("client-cert")?;
+    let client_key = call.get_flag::<String>("client-key")?;
+
+    if !call.has_flag("tls")? && ca_cert.is_none() && client_cert.is_none() && client_key.is_none()
+    {
+        return Ok(None);
+    }
+
+    let mut ssl = SslContext::new();
+    if let Some(path) = ca_cert {
+        let pem = fs::read_to_string(&path).map_err(|err| {
+            LabeledError::new(format!("Failed to read CA certificate: {err}"))
+                .with_label("while reading --ca-cert", span)
+        })?;
+        ssl.add_trusted_cert(&pem).label(span)?;
+    }
+    if let Some(path) = client_cert {
+        let pem = fs::read_to_string(&path).map_err(|err| {
+            LabeledError::new(format!("Failed to read client certificate: {err}"))
+                .with_label("while reading --client-cert", span)
+        })?;
+        ssl.set_cert(&pem).label(span)?;
+    }
+    if let Some(path) = client_key {
+        let pem = fs::read_to_string(&path).map_err(|err| {
+            LabeledError::new(format!("Failed to read client key: {err}"))
+                .with_label("while reading --client-key", span)
+        })?;
+        ssl.set_private_key(&pem, "").label(span)?;
+    }
+    ssl.set_verify_flags(SslVerifyFlag::PEER_CERT);
+
+    Ok(Some(ssl))
+}
+
+/// Where a bound parameter lives on the statement: by positional index (`--params`) or by
+/// column name (pipeline-input writes).
+enum BindTarget<'a> {
+    Index(usize),
+    Name(&'a str),
+}
+
+/// Narrows a Nu `Int` (`i64`) to the target CQL integer width, erroring instead of truncating
+/// when the value doesn't fit.
+fn checked_int<T>(val: i64, span: Span) -> Result<T, LabeledError>
+where
+    T: TryFrom<i64>,
+{
+    T::try_from(val).map_err(|_| {
+        LabeledError::new("Integer out of range")
+            .with_label(format!("{val} does not fit in the target CQL integer type"), span)
+    })
+}
+
+/// Binds a Nushell `Value` to a prepared statement parameter, converting in the reverse
+/// direction of [`get_cassandra_value`] based on the parameter's declared CQL type.
+fn bind_nu_value(
+    statement: &mut cassandra_cpp::Statement,
+    target: BindTarget,
+    param_type: cassandra_cpp::ValueType,
+    value: &Value,
+) -> Result<(), LabeledError> {
+    let span = value.span();
+
+    macro_rules! bind {
+        ($by_index:ident, $by_name:ident, $val:expr) => {
+            match target {
+                BindTarget::Index(index) => statement.$by_index(index, $val),
+                BindTarget::Name(name) => statement.$by_name(name, $val),
+            }
+            .label(span)
+        };
+    }
+
+    match (param_type, value) {
+        (cassandra_cpp::ValueType::BIGINT | cassandra_cpp::ValueType::COUNTER, Value::Int { val, .. }) => {
+            bind!(bind_int64, bind_int64_by_name, *val)
+        }
+        (cassandra_cpp::ValueType::INT, Value::Int { val, .. }) => {
+            bind!(bind_int32, bind_int32_by_name, checked_int::<i32>(*val, span)?)
+        }
+        (cassandra_cpp::ValueType::SMALL_INT, Value::Int { val, .. }) => {
+            bind!(bind_int16, bind_int16_by_name, checked_int::<i16>(*val, span)?)
+        }
+        (cassandra_cpp::ValueType::TINY_INT, Value::Int { val, .. }) => {
+            bind!(bind_int8, bind_int8_by_name, checked_int::<i8>(*val, span)?)
+        }
+        (cassandra_cpp::ValueType::DOUBLE, Value::Float { val, .. }) => {
+            bind!(bind_double, bind_double_by_name, *val)
+        }
+        (cassandra_cpp::ValueType::FLOAT, Value::Float { val, .. }) => {
+            bind!(bind_float, bind_float_by_name, *val as f32)
+        }
+        (cassandra_cpp::ValueType::BOOLEAN, Value::Bool { val, .. }) => {
+            bind!(bind_bool, bind_bool_by_name, *val)
+        }
+        (cassandra_cpp::ValueType::BLOB, Value::Binary { val, .. }) => {
+            bind!(bind_bytes, bind_bytes_by_name, val.clone())
+        }
+        (cassandra_cpp::ValueType::TIMESTAMP, Value::Date { val, .. }) => {
+            bind!(bind_int64, bind_int64_by_name, val.timestamp_millis())
+        }
+        (cassandra_cpp::ValueType::UUID | cassandra_cpp::ValueType::TIMEUUID, Value::String { val, .. }) => {
+            let uuid = val
+                .parse::<Uuid>()
+                .map_err(|err| LabeledError::new(format!("Invalid UUID: {err}")).with_label("expected a UUID string", span))?;
+            bind!(bind_uuid, bind_uuid_by_name, uuid.into())
+        }
+        (
+            cassandra_cpp::ValueType::TEXT | cassandra_cpp::ValueType::VARCHAR | cassandra_cpp::ValueType::ASCII,
+            Value::String { val, .. },
+        ) => bind!(bind_string, bind_string_by_name, val),
+        (expected, value) => Err(LabeledError::new("Cannot bind parameter")
+            .with_label(
+                format!("expected {:?}, got {}", expected, value.get_type()),
+                span,
+            )),
+    }
+}
+
+/// How many times to poll `system_traces.events` for a tracing session before giving up.
+const TRACE_FETCH_ATTEMPTS: u32 = 5;
+/// Delay before the second poll (the first poll runs immediately), doubled before each later one.
+const TRACE_FETCH_INITIAL_DELAY: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// Prints the server-side trace events, warnings, and custom payload for a `--trace`d query to
+/// stderr by following up with a query against `system_traces.events`.
+async fn report_trace(
+    session: &cassandra_cpp::Session,
+    result: &cassandra_cpp::CassResult,
+    payload: Option<&cassandra_cpp::CustomPayload>,
+    span: Span,
+) -> Result<(), LabeledError> {
+    for warning in result.warnings() {
+        eprintln!("cassandra: warning: {warning}");
+    }
+    if let Some(payload) = payload {
+        if !payload.is_empty() {
+            eprintln!("cassandra: custom payload: {payload:?}");
+        }
+    }
+    let Some(tracing_id) = result.tracing_id() else {
+        return Ok(());
+    };
+    eprintln!("cassandra: trace {tracing_id}");
+
+    let prepared = session
+        .prepare("SELECT activity, source, source_elapsed FROM system_traces.events WHERE session_id = ?")
+        .await
+        .label(span)?;
+
+    // The coordinator writes trace events asynchronously, well after the client's response
+    // arrives, so a single immediate read of system_traces.events routinely finds nothing.
+    // Poll with a short backoff instead of reading once.
+    let mut delay = TRACE_FETCH_INITIAL_DELAY;
+    let mut rows = Vec::new();
+    for attempt in 0..TRACE_FETCH_ATTEMPTS {
+        if attempt > 0 {
+            tokio::time::sleep(delay).await;
+            delay *= 2;
+        }
+        let mut trace_statement = prepared.bind();
+        trace_statement.bind_uuid(0, tracing_id).label(span)?;
+        let (trace_result, _) = session
+            .execute_with_payloads(&trace_statement)
+            .await
+            .label(span)?;
+        rows.clear();
+        let mut iter = trace_result.iter();
+        while let Some(row) = iter.next() {
+            let activity = get_cassandra_value(row.get_column(0).label(span)?, span);
+            let source = get_cassandra_value(row.get_column(1).label(span)?, span);
+            let elapsed = get_cassandra_value(row.get_column(2).label(span)?, span);
+            rows.push((source, elapsed, activity));
+        }
+        if !rows.is_empty() {
+            break;
+        }
+    }
+    for (source, elapsed, activity) in rows {
+        eprintln!("cassandra: trace   {source:?} {elapsed:?} {activity:?}");
+    }
+    Ok(())
+}
+
+/// Consistency, serial consistency, and request-timeout settings parsed from `--consistency`,
+/// `--serial-consistency`, and `--timeout`, shared between the read path and the write/batch path.
+struct StatementOptions {
+    consistency: cassandra_cpp::Consistency,
+    serial_consistency: Option<cassandra_cpp::Consistency>,
+    timeout_ms: Option<u64>,
+}
+
+impl StatementOptions {
+    fn from_call(call: &EvaluatedCall, span: Span) -> Result<Self, LabeledError> {
+        let consistency = call
+            .get_flag::<String>("consistency")?
+            .map(|value| consistency_from_str(&value, span))
+            .transpose()?
+            .unwrap_or(cassandra_cpp::Consistency::LOCAL_ONE);
+        let serial_consistency = call
+            .get_flag::<String>("serial-consistency")?
+            .map(|value| serial_consistency_from_str(&value, span))
+            .transpose()?;
+        let timeout_ms = match call.get_flag_value("timeout") {
+            Some(Value::Duration { val, .. }) => {
+                if val < 0 {
+                    return Err(LabeledError::new("Invalid timeout")
+                        .with_label("--timeout must not be negative", span));
+                }
+                Some((val / 1_000_000) as u64)
+            }
+            _ => None,
+        };
+        Ok(Self {
+            consistency,
+            serial_consistency,
+            timeout_ms,
+        })
+    }
+
+    fn apply_to_statement(
+        &self,
+        statement: &mut cassandra_cpp::Statement,
+        span: Span,
+    ) -> Result<(), LabeledError> {
+        statement.set_consistency(self.consistency).label(span)?;
+        if let Some(serial) = self.serial_consistency {
+            statement.set_serial_consistency(serial).label(span)?;
+        }
+        if let Some(timeout_ms) = self.timeout_ms {
+            statement.set_request_timeout(timeout_ms).label(span)?;
+        }
+        Ok(())
+    }
+
+    fn apply_to_batch(
+        &self,
+        batch: &mut cassandra_cpp::Batch,
+        span: Span,
+    ) -> Result<(), LabeledError> {
+        batch.set_consistency(self.consistency).label(span)?;
+        if let Some(serial) = self.serial_consistency {
+            batch.set_serial_consistency(serial).label(span)?;
+        }
+        if let Some(timeout_ms) = self.timeout_ms {
+            batch.set_request_timeout(timeout_ms).label(span)?;
+        }
+        Ok(())
+    }
+}
+
+fn consistency_from_str(
+    value: &str,
+    span: Span,
+) -> Result<cassandra_cpp::Consistency, LabeledError> {
+    use cassandra_cpp::Consistency;
+    Ok(match value {
+        "any" => Consistency::ANY,
+        "one" => Consistency::ONE,
+        "two" => Consistency::TWO,
+        "three" => Consistency::THREE,
+        "quorum" => Consistency::QUORUM,
+        "all" => Consistency::ALL,
+        "local-quorum" => Consistency::LOCAL_QUORUM,
+        "each-quorum" => Consistency::EACH_QUORUM,
+        "local-one" => Consistency::LOCAL_ONE,
+        "serial" => Consistency::SERIAL,
+        "local-serial" => Consistency::LOCAL_SERIAL,
+        other => {
+            return Err(LabeledError::new(format!("Unknown consistency level: {other}"))
+                .with_label(
+                    "expected one|two|three|quorum|all|local-one|local-quorum|each-quorum|any|serial|local-serial",
+                    span,
+                ))
+        }
+    })
+}
+
+fn serial_consistency_from_str(
+    value: &str,
+    span: Span,
+) -> Result<cassandra_cpp::Consistency, LabeledError> {
+    use cassandra_cpp::Consistency;
+    Ok(match value {
+        "serial" => Consistency::SERIAL,
+        "local-serial" => Consistency::LOCAL_SERIAL,
+        other => {
+            return Err(LabeledError::new(format!("Unknown serial consistency level: {other}"))
+                .with_label("expected serial|local-serial", span))
+        }
+    })
+}
+
+/// Executes `query` once per input row, either as a single CQL `BATCH` (`--batch`) or as a
+/// sequence of individually-executed prepared statements, binding each row's fields to the
+/// statement by column name. Returns the number of rows applied.
+async fn run_writes(
+    session: &cassandra_cpp::Session,
+    query: &str,
+    rows: Vec<Value>,
+    call: &EvaluatedCall,
+    options: &StatementOptions,
+    trace: bool,
+    span: Span,
+) -> Result<PipelineData, LabeledError> {
+    if rows.is_empty() {
+        return Ok(PipelineData::Value(Value::int(0, span), None));
+    }
+
+    let prepared = session.prepare(query).await.label(span)?;
+
+    if call.has_flag("batch")? {
+        let mut batch = cassandra_cpp::Batch::new(batch_type(call)?);
+        options.apply_to_batch(&mut batch, span)?;
+        if trace {
+            batch.set_tracing(true).label(span)?;
+        }
+        for row in &rows {
+            let mut statement = bind_row(&prepared, row, span)?;
+            options.apply_to_statement(&mut statement, span)?;
+            batch.add_statement(&statement).label(span)?;
+        }
+        let result = session.execute_batch(&batch).await.label(span)?;
+        if trace {
+            report_trace(session, &result, None, span).await?;
+        }
+    } else {
+        for row in &rows {
+            let mut statement = bind_row(&prepared, row, span)?;
+            options.apply_to_statement(&mut statement, span)?;
+            if trace {
+                statement.set_tracing(true).label(span)?;
+            }
+            let (result, payload) = session.execute_with_payloads(&statement).await.label(span)?;
+            if trace {
+                report_trace(session, &result, Some(&payload), span).await?;
+            }
+        }
+    }
+    Ok(PipelineData::Value(Value::int(rows.len() as i64, span), None))
+}
+
+fn batch_type(call: &EvaluatedCall) -> Result<cassandra_cpp::BatchType, LabeledError> {
+    batch_type_from_str(call.get_flag::<String>("batch-type")?.as_deref(), call.head)
+}
+
+fn batch_type_from_str(
+    value: Option<&str>,
+    span: Span,
+) -> Result<cassandra_cpp::BatchType, LabeledError> {
+    match value {
+        None | Some("logged") => Ok(cassandra_cpp::BatchType::LOGGED),
+        Some("unlogged") => Ok(cassandra_cpp::BatchType::UNLOGGED),
+        Some("counter") => Ok(cassandra_cpp::BatchType::COUNTER),
+        Some(other) => Err(LabeledError::new(format!("Unknown batch type: {other}"))
+            .with_label("expected logged, unlogged, or counter", span)),
+    }
+}
+
+fn bind_row(
+    prepared: &cassandra_cpp::PreparedStatement,
+    row: &Value,
+    span: Span,
+) -> Result<cassandra_cpp::Statement, LabeledError> {
+    let record = row
+        .as_record()
+        .map_err(|err| LabeledError::new(err.to_string()).with_label("expected a record", row.span()))?;
+    let mut statement = prepared.bind();
+    for (name, value) in record.iter() {
+        let param_type = prepared
+            .parameter_data_type_by_name(name)
+            .label(span)?
+            .get_type();
+        bind_nu_value(&mut statement, BindTarget::Name(name), param_type, value)?;
+    }
+    Ok(statement)
+}
+
 fn get_cassandra_value(val: cassandra_cpp::Value, span: Span) -> Value {
     match val.get_type() {
         cassandra_cpp::ValueType::ASCII => {
@@ -172,7 +712,7 @@ fn get_cassandra_value(val: cassandra_cpp::Value, span: Span) -> Value {
                 span,
             )
         }),
-        cassandra_cpp::ValueType::UUID => val
+        cassandra_cpp::ValueType::UUID | cassandra_cpp::ValueType::TIMEUUID => val
             .get_bytes()
             .label(span)
             .and_then(|v| Uuid::from_slice(&v).map_err(|err| LabeledError::new(err.to_string())))
@@ -181,17 +721,28 @@ fn get_cassandra_value(val: cassandra_cpp::Value, span: Span) -> Value {
             val.get_string().label(span).map(|v| Value::string(v, span))
         }
         cassandra_cpp::ValueType::VARINT => val.get_i64().label(span).map(|v| Value::int(v, span)),
-        cassandra_cpp::ValueType::TIMEUUID => {
-            val.get_string().label(span).map(|v| Value::string(v, span))
-        }
-        cassandra_cpp::ValueType::INET => {
-            val.get_string().label(span).map(|v| Value::string(v, span))
-        }
-        cassandra_cpp::ValueType::DATE => {
-            val.get_string().label(span).map(|v| Value::string(v, span))
-        }
+        cassandra_cpp::ValueType::INET => val.get_inet().label(span).and_then(|inet| {
+            inet.to_string()
+                .parse::<std::net::IpAddr>()
+                .map_err(|err| LabeledError::new(format!("Invalid INET value: {err}")))
+        }).map(|ip| Value::string(ip.to_string(), span)),
+        cassandra_cpp::ValueType::DATE => val.get_u32().label(span).and_then(|days| {
+            let days_since_epoch = days as i64 - (1i64 << 31);
+            chrono::NaiveDate::from_ymd_opt(1970, 1, 1)
+                .expect("valid epoch")
+                .checked_add_signed(chrono::Duration::days(days_since_epoch))
+                .ok_or_else(|| LabeledError::new("Cassandra date out of range"))
+        }).map(|date| {
+            Value::date(
+                date.and_hms_opt(0, 0, 0)
+                    .expect("valid time")
+                    .and_utc()
+                    .fixed_offset(),
+                span,
+            )
+        }),
         cassandra_cpp::ValueType::TIME => {
-            val.get_string().label(span).map(|v| Value::string(v, span))
+            val.get_i64().label(span).map(|ns| Value::duration(ns, span))
         }
         cassandra_cpp::ValueType::SMALL_INT => val
             .get_i16()
@@ -222,6 +773,13 @@ fn get_cassandra_value(val: cassandra_cpp::Value, span: Span) -> Value {
             }
             Value::record(record, span)
         }),
+        cassandra_cpp::ValueType::UDT => val.get_udt().label(span).map(|mut udt_iter| {
+            let mut record = Record::new();
+            while let Some((name, field)) = udt_iter.next() {
+                record.insert(name, get_cassandra_value(field, span));
+            }
+            Value::record(record, span)
+        }),
         other => Err(LabeledError::new("Unsupported Cassandra type")
             .with_label(format!("{:?}", other), span)),
     }
@@ -265,3 +823,63 @@ fn main() {
         }))
         .expect("panic in runtime");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistency_from_str_parses_known_levels() {
+        assert_eq!(
+            consistency_from_str("quorum", Span::test_data()).unwrap(),
+            cassandra_cpp::Consistency::QUORUM
+        );
+        assert_eq!(
+            consistency_from_str("local-one", Span::test_data()).unwrap(),
+            cassandra_cpp::Consistency::LOCAL_ONE
+        );
+    }
+
+    #[test]
+    fn consistency_from_str_rejects_unknown_levels() {
+        assert!(consistency_from_str("bogus", Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn serial_consistency_from_str_accepts_only_serial_levels() {
+        assert_eq!(
+            serial_consistency_from_str("serial", Span::test_data()).unwrap(),
+            cassandra_cpp::Consistency::SERIAL
+        );
+        assert_eq!(
+            serial_consistency_from_str("local-serial", Span::test_data()).unwrap(),
+            cassandra_cpp::Consistency::LOCAL_SERIAL
+        );
+        assert!(serial_consistency_from_str("quorum", Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn batch_type_from_str_defaults_to_logged() {
+        assert_eq!(
+            batch_type_from_str(None, Span::test_data()).unwrap(),
+            cassandra_cpp::BatchType::LOGGED
+        );
+    }
+
+    #[test]
+    fn batch_type_from_str_parses_known_types() {
+        assert_eq!(
+            batch_type_from_str(Some("unlogged"), Span::test_data()).unwrap(),
+            cassandra_cpp::BatchType::UNLOGGED
+        );
+        assert_eq!(
+            batch_type_from_str(Some("counter"), Span::test_data()).unwrap(),
+            cassandra_cpp::BatchType::COUNTER
+        );
+    }
+
+    #[test]
+    fn batch_type_from_str_rejects_unknown_types() {
+        assert!(batch_type_from_str(Some("bogus"), Span::test_data()).is_err());
+    }
+}